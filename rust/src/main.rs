@@ -1,6 +1,6 @@
 use clap::{Arg, Command};
-use regex::Regex;
-use serde_json::Value;
+use regex::{Regex, RegexSet};
+use serde_json::{Map, Value};
 use std::collections::VecDeque;
 use std::fs::File;
 use std::io::{self, Read};
@@ -22,6 +22,14 @@ struct Args {
     delimiter: String,
     indent: Option<usize>,
     count: bool,
+    jsonpath: Option<String>,
+    glob: Vec<String>,
+    iglob: Vec<String>,
+    format: Option<String>,
+    output: String,
+    path_query: Option<String>,
+    diff: Option<String>,
+    contains: Option<String>,
 }
 
 fn parse_args() -> Args {
@@ -108,6 +116,58 @@ fn parse_args() -> Args {
                 .help("Print count of matches")
                 .action(clap::ArgAction::SetTrue),
         )
+        .arg(
+            Arg::new("jsonpath")
+                .long("jsonpath")
+                .help("JSONPath expression (e.g., '$..items[?(@.price < 10)].name')")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("glob")
+                .long("glob")
+                .short('g')
+                .help("Glob pattern for key paths ('*' within a segment, '**' across segments)")
+                .num_args(1)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("iglob")
+                .long("iglob")
+                .help("Case-insensitive glob pattern for key paths")
+                .num_args(1)
+                .action(clap::ArgAction::Append),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .help("Input format: json, yaml, toml, ini, rec, or auto (default: auto-detect from extension, then content)")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .help("Output format: lines, json, ndjson, or paths")
+                .default_value("lines")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("path")
+                .long("path")
+                .help("Path expression with literal/index/'*' segments (e.g., 'phoneNumbers::*::number')")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("diff")
+                .long("diff")
+                .help("Compare the input against another JSON document, reporting added/removed/changed paths")
+                .num_args(1),
+        )
+        .arg(
+            Arg::new("contains")
+                .long("contains")
+                .help("Check that every key/value in a template JSON file is present in the input at the same path")
+                .num_args(1),
+        )
         .get_matches();
 
     Args {
@@ -147,6 +207,23 @@ fn parse_args() -> Args {
             .unwrap_or("::".to_string()),
         indent: matches.get_one::<usize>("indent").copied(),
         count: matches.get_flag("count"),
+        jsonpath: matches.get_one::<String>("jsonpath").cloned(),
+        glob: matches
+            .get_many::<String>("glob")
+            .map(|vals| vals.cloned().collect())
+            .unwrap_or_default(),
+        iglob: matches
+            .get_many::<String>("iglob")
+            .map(|vals| vals.cloned().collect())
+            .unwrap_or_default(),
+        format: matches.get_one::<String>("format").cloned(),
+        output: matches
+            .get_one::<String>("output")
+            .cloned()
+            .unwrap_or_else(|| "lines".to_string()),
+        path_query: matches.get_one::<String>("path").cloned(),
+        diff: matches.get_one::<String>("diff").cloned(),
+        contains: matches.get_one::<String>("contains").cloned(),
     }
 }
 
@@ -154,8 +231,225 @@ fn has_stdin() -> bool {
     !atty::is(atty::Stream::Stdin)
 }
 
-fn load_json(file_path: &Option<String>) -> Result<Value, Box<dyn std::error::Error>> {
-    let json_str = match file_path {
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum InputFormat {
+    Json,
+    Yaml,
+    Toml,
+    Ini,
+    Rec,
+}
+
+impl InputFormat {
+    fn label(&self) -> &'static str {
+        match self {
+            InputFormat::Json => "JSON",
+            InputFormat::Yaml => "YAML",
+            InputFormat::Toml => "TOML",
+            InputFormat::Ini => "INI",
+            InputFormat::Rec => "rec",
+        }
+    }
+}
+
+// Sniffs a format when the extension didn't tell us anything (stdin, extension-less
+// files). This is necessarily a heuristic: JSON/YAML flow style and TOML/INI share
+// enough syntax that only the content's general shape can disambiguate them.
+fn sniff_format(content: &str) -> InputFormat {
+    let trimmed = content.trim_start();
+    if trimmed.starts_with('{') {
+        return InputFormat::Json;
+    }
+    if trimmed.starts_with("---") {
+        return InputFormat::Yaml;
+    }
+    // A leading '[' is ambiguous with an INI/TOML section header (`[server]`), so only
+    // claim JSON here when the content actually parses as one; otherwise fall through
+    // to the line-based scan below.
+    if trimmed.starts_with('[') && serde_json::from_str::<Value>(trimmed).is_ok() {
+        return InputFormat::Json;
+    }
+
+    for line in content.lines() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if line.starts_with('[') && line.ends_with(']') {
+            return InputFormat::Ini;
+        }
+        if line.contains('=') {
+            return InputFormat::Toml;
+        }
+        if line.contains(':') {
+            return InputFormat::Yaml;
+        }
+        break;
+    }
+
+    InputFormat::Json
+}
+
+fn detect_format(file_path: &Option<String>, content: &str) -> InputFormat {
+    if let Some(path) = file_path {
+        if path != "-" {
+            match Path::new(path).extension().and_then(|e| e.to_str()) {
+                Some("yaml") | Some("yml") => return InputFormat::Yaml,
+                Some("toml") => return InputFormat::Toml,
+                Some("ini") => return InputFormat::Ini,
+                Some("rec") => return InputFormat::Rec,
+                Some("json") => return InputFormat::Json,
+                _ => {}
+            }
+        }
+    }
+    sniff_format(content)
+}
+
+fn resolve_format(
+    format: &Option<String>,
+    file_path: &Option<String>,
+    content: &str,
+) -> Result<InputFormat, Box<dyn std::error::Error>> {
+    match format.as_deref() {
+        None | Some("auto") => Ok(detect_format(file_path, content)),
+        Some("json") => Ok(InputFormat::Json),
+        Some("yaml") => Ok(InputFormat::Yaml),
+        Some("toml") => Ok(InputFormat::Toml),
+        Some("ini") => Ok(InputFormat::Ini),
+        Some("rec") => Ok(InputFormat::Rec),
+        Some(other) => {
+            eprintln!(
+                "Error: Unknown format '{}': expected json, yaml, toml, ini, rec, or auto",
+                other
+            );
+            Err(format!("Unknown format '{}'", other).into())
+        }
+    }
+}
+
+fn flush_record(current: &mut Map<String, Value>, records: &mut Vec<Value>) {
+    if !current.is_empty() {
+        records.push(Value::Object(std::mem::take(current)));
+    }
+}
+
+// Parses the recutils `.rec` format: records are separated by blank lines, fields are
+// `Field: value` lines, `%rec:` declares the type carried into following records as
+// `rec_type`, and lines starting with `+` continue the previous field's value.
+fn parse_recfile(content: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut records = Vec::new();
+    let mut current: Map<String, Value> = Map::new();
+    let mut last_field: Option<String> = None;
+    let mut rec_type: Option<String> = None;
+
+    for line in content.lines() {
+        if line.trim().is_empty() {
+            flush_record(&mut current, &mut records);
+            last_field = None;
+            continue;
+        }
+
+        if let Some(rest) = line.strip_prefix('+') {
+            if let Some(field) = &last_field {
+                if let Some(Value::String(existing)) = current.get_mut(field) {
+                    existing.push('\n');
+                    existing.push_str(rest.trim_start());
+                }
+            }
+            continue;
+        }
+
+        let (name, value) = line
+            .split_once(':')
+            .ok_or_else(|| format!("Invalid recfile line: '{}'", line))?;
+        let name = name.trim();
+        let value = value.trim().to_string();
+
+        if name == "%rec" {
+            flush_record(&mut current, &mut records);
+            rec_type = Some(value);
+            last_field = None;
+            continue;
+        }
+
+        if current.is_empty() {
+            if let Some(t) = &rec_type {
+                current.insert("rec_type".to_string(), Value::String(t.clone()));
+            }
+        }
+
+        match current.get_mut(name) {
+            Some(Value::Array(arr)) => arr.push(Value::String(value)),
+            Some(existing) => {
+                let prior = existing.clone();
+                current.insert(name.to_string(), Value::Array(vec![prior, Value::String(value)]));
+            }
+            None => {
+                current.insert(name.to_string(), Value::String(value));
+            }
+        }
+        last_field = Some(name.to_string());
+    }
+
+    flush_record(&mut current, &mut records);
+    Ok(Value::Array(records))
+}
+
+// Parses a minimal INI dialect: `[section]` headers, `key = value` / `key: value`
+// lines, and `;`/`#` comments. Keys before the first section land on the root object;
+// each section becomes a nested object keyed by its section name.
+fn parse_ini(content: &str) -> Result<Value, Box<dyn std::error::Error>> {
+    let mut root = Map::new();
+    let mut sections: Map<String, Value> = Map::new();
+    let mut current_section: Option<String> = None;
+
+    for raw_line in content.lines() {
+        let line = raw_line.trim();
+        if line.is_empty() || line.starts_with(';') || line.starts_with('#') {
+            continue;
+        }
+
+        if line.starts_with('[') && line.ends_with(']') {
+            let name = line[1..line.len() - 1].trim().to_string();
+            sections
+                .entry(name.clone())
+                .or_insert_with(|| Value::Object(Map::new()));
+            current_section = Some(name);
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .or_else(|| line.split_once(':'))
+            .ok_or_else(|| format!("Invalid INI line: '{}'", line))?;
+        let key = key.trim().to_string();
+        let value = Value::String(value.trim().to_string());
+
+        match &current_section {
+            Some(section) => {
+                if let Some(Value::Object(map)) = sections.get_mut(section) {
+                    map.insert(key, value);
+                }
+            }
+            None => {
+                root.insert(key, value);
+            }
+        }
+    }
+
+    for (name, section_value) in sections {
+        root.insert(name, section_value);
+    }
+
+    Ok(Value::Object(root))
+}
+
+fn load_input(
+    file_path: &Option<String>,
+    format: &Option<String>,
+) -> Result<Value, Box<dyn std::error::Error>> {
+    let content = match file_path {
         Some(path) if path != "-" => {
             let file = File::open(path)?;
             let mut contents = String::new();
@@ -169,13 +463,26 @@ fn load_json(file_path: &Option<String>) -> Result<Value, Box<dyn std::error::Er
         }
     };
 
-    serde_json::from_str(&json_str).map_err(|e| {
+    let input_format = resolve_format(format, file_path, &content)?;
+
+    let parsed: Result<Value, Box<dyn std::error::Error>> = match input_format {
+        InputFormat::Json => serde_json::from_str(&content).map_err(|e| e.into()),
+        InputFormat::Yaml => serde_yaml::from_str(&content).map_err(|e| e.into()),
+        InputFormat::Toml => toml::from_str::<toml::Value>(&content)
+            .map_err(|e| e.into())
+            .and_then(|v| serde_json::to_value(v).map_err(|e| e.into())),
+        InputFormat::Ini => parse_ini(&content),
+        InputFormat::Rec => parse_recfile(&content),
+    };
+
+    parsed.map_err(|e| {
         eprintln!(
-            "Error: Invalid JSON in {}: {}",
+            "Error: Invalid {} in {}: {}",
+            input_format.label(),
             file_path.as_ref().map_or("stdin", |p| p),
             e
         );
-        e.into()
+        e
     })
 }
 
@@ -302,9 +609,129 @@ fn normalize_key(key: &str, delimiter: &str) -> String {
     parts.join(delimiter)
 }
 
+// Translates a shell-style glob over key paths into an anchored regex. `*` matches
+// within a single path segment, `**` matches across any number of segments, `?` matches
+// a single character, and `[...]`/`[!...]` character classes pass through to the regex
+// engine unchanged (with `!` rewritten to `^` for negation). Everything else is escaped,
+// so literal occurrences of `delimiter` in the glob naturally become the segment join.
+fn glob_to_regex(pattern: &str, delimiter: &str) -> String {
+    let mut delim_chars: Vec<char> = delimiter.chars().collect();
+    delim_chars.dedup();
+    let not_delim: String = delim_chars
+        .iter()
+        .map(|c| regex::escape(&c.to_string()))
+        .collect();
+
+    let chars: Vec<char> = pattern.chars().collect();
+    let mut regex = String::from("^");
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '*' if chars.get(i + 1) == Some(&'*') => {
+                let before: String = chars[..i].iter().collect();
+                let after: String = chars[i + 2..].iter().collect();
+                if !delimiter.is_empty()
+                    && after.starts_with(delimiter)
+                    && (before.is_empty() || before.ends_with(delimiter))
+                {
+                    // A whole `**` segment may also stand for zero segments.
+                    regex.push_str(&format!("(?:.*{})?", regex::escape(delimiter)));
+                    i += 2 + delimiter.chars().count();
+                } else {
+                    regex.push_str(".*");
+                    i += 2;
+                }
+            }
+            '*' => {
+                regex.push_str(&format!("[^{}]*", not_delim));
+                i += 1;
+            }
+            '?' => {
+                regex.push_str(&format!("[^{}]", not_delim));
+                i += 1;
+            }
+            '[' => match chars[i..].iter().position(|&c| c == ']') {
+                Some(offset) => {
+                    let close = i + offset;
+                    let inner: String = chars[i + 1..close].iter().collect();
+                    let inner = if let Some(rest) = inner.strip_prefix('!') {
+                        format!("^{}", rest)
+                    } else {
+                        inner
+                    };
+                    regex.push('[');
+                    regex.push_str(&inner);
+                    regex.push(']');
+                    i = close + 1;
+                }
+                None => {
+                    regex.push_str(&regex::escape("["));
+                    i += 1;
+                }
+            },
+            c => {
+                regex.push_str(&regex::escape(&c.to_string()));
+                i += 1;
+            }
+        }
+    }
+    regex.push('$');
+    regex
+}
+
+// Borrowed from globset's MatchStrategy: classify an anchored key pattern up front so
+// `search_keys` can skip the regex engine entirely for the common literal/prefix/suffix
+// cases, which dominate real-world `--key`/`--glob` usage.
+#[derive(Debug, Clone)]
+enum MatchStrategy {
+    Literal(String),
+    Suffix(String),
+    Prefix(String),
+    Regex(Regex),
+}
+
+fn is_plain_literal(s: &str) -> bool {
+    !s.chars().any(|c| "\\^$.|?*+()[]{}".contains(c))
+}
+
+fn classify_key_pattern(anchored: &str) -> Result<MatchStrategy, regex::Error> {
+    if let Some(body) = anchored.strip_prefix('^').and_then(|s| s.strip_suffix('$')) {
+        if is_plain_literal(body) {
+            return Ok(MatchStrategy::Literal(body.to_string()));
+        }
+        if let Some(rest) = body.strip_prefix(".*") {
+            if is_plain_literal(rest) {
+                return Ok(MatchStrategy::Suffix(rest.to_string()));
+            }
+        }
+        if let Some(rest) = body.strip_suffix(".*") {
+            if is_plain_literal(rest) {
+                return Ok(MatchStrategy::Prefix(rest.to_string()));
+            }
+        }
+    }
+
+    Ok(MatchStrategy::Regex(Regex::new(anchored)?))
+}
+
+fn matches_strategy(current_prefix: &str, delimiter: &str, strategy: &MatchStrategy) -> bool {
+    match strategy {
+        // A bare literal also matches against just the final path segment, so e.g.
+        // `street` finds `address::street` the same way a plain positional key pattern
+        // always has.
+        MatchStrategy::Literal(lit) => {
+            current_prefix == lit || current_prefix.rsplit(delimiter).next() == Some(lit.as_str())
+        }
+        MatchStrategy::Suffix(suffix) => current_prefix.ends_with(suffix.as_str()),
+        MatchStrategy::Prefix(prefix) => current_prefix.starts_with(prefix.as_str()),
+        MatchStrategy::Regex(_) => false,
+    }
+}
+
 fn search_keys(
     data: &Value,
-    key_patterns: &[Regex],
+    strategies: &[MatchStrategy],
+    key_set: &RegexSet,
     delimiter: &str,
     prefix: &str,
 ) -> Vec<Match> {
@@ -318,21 +745,16 @@ fn search_keys(
                 } else {
                     format!("{}{}{}", prefix, delimiter, k)
                 };
-                for pattern in key_patterns {
-                    let pattern_str = pattern.to_string();
-                    let clean_pattern = pattern_str
-                        .strip_prefix('^')
-                        .and_then(|s| s.strip_suffix('$'))
-                        .unwrap_or(&pattern_str);
-                    if pattern.is_match(&current_prefix) || k == clean_pattern {
-                        matches.push(Match {
-                            key: normalize_key(&current_prefix, delimiter),
-                            value: v.clone(),
-                        });
-                    }
+                let is_match = strategies.iter().any(|s| matches_strategy(&current_prefix, delimiter, s))
+                    || key_set.is_match(&current_prefix);
+                if is_match {
+                    matches.push(Match {
+                        key: normalize_key(&current_prefix, delimiter),
+                        value: v.clone(),
+                    });
                 }
                 let new_prefix = current_prefix;
-                matches.extend(search_keys(v, key_patterns, delimiter, &new_prefix));
+                matches.extend(search_keys(v, strategies, key_set, delimiter, &new_prefix));
             }
         }
         Value::Array(arr) => {
@@ -342,16 +764,16 @@ fn search_keys(
                 } else {
                     format!("{}{}{}", prefix, delimiter, i)
                 };
-                for pattern in key_patterns {
-                    if pattern.is_match(&current_prefix) {
-                        matches.push(Match {
-                            key: normalize_key(&current_prefix, delimiter),
-                            value: v.clone(),
-                        });
-                    }
+                let is_match = strategies.iter().any(|s| matches_strategy(&current_prefix, delimiter, s))
+                    || key_set.is_match(&current_prefix);
+                if is_match {
+                    matches.push(Match {
+                        key: normalize_key(&current_prefix, delimiter),
+                        value: v.clone(),
+                    });
                 }
                 let new_prefix = current_prefix;
-                matches.extend(search_keys(v, key_patterns, delimiter, &new_prefix));
+                matches.extend(search_keys(v, strategies, key_set, delimiter, &new_prefix));
             }
         }
         _ => {}
@@ -362,7 +784,7 @@ fn search_keys(
 
 fn search_values(
     data: &Value,
-    value_patterns: &[Regex],
+    value_set: &RegexSet,
     delimiter: &str,
     prefix: &str,
 ) -> Vec<Match> {
@@ -376,7 +798,7 @@ fn search_values(
                 } else {
                     format!("{}{}{}", prefix, delimiter, k)
                 };
-                matches.extend(search_values(v, value_patterns, delimiter, &new_prefix));
+                matches.extend(search_values(v, value_set, delimiter, &new_prefix));
             }
         }
         Value::Array(arr) => {
@@ -386,105 +808,817 @@ fn search_values(
                 } else {
                     format!("{}{}{}", prefix, delimiter, i)
                 };
-                matches.extend(search_values(v, value_patterns, delimiter, &new_prefix));
+                matches.extend(search_values(v, value_set, delimiter, &new_prefix));
             }
         }
-        Value::String(s) => {
-            for pattern in value_patterns {
-                if pattern.is_match(s) {
-                    matches.push(Match {
-                        key: normalize_key(prefix.trim_end_matches(delimiter), delimiter),
-                        value: data.clone(),
-                    });
+        Value::String(s) if value_set.is_match(s) => {
+            matches.push(Match {
+                key: normalize_key(prefix.trim_end_matches(delimiter), delimiter),
+                value: data.clone(),
+            });
+        }
+        Value::Number(_) | Value::Bool(_) if value_set.is_match(&data.to_string()) => {
+            matches.push(Match {
+                key: normalize_key(prefix.trim_end_matches(delimiter), delimiter),
+                value: data.clone(),
+            });
+        }
+        _ => {}
+    }
+
+    matches
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum FilterOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+#[derive(Debug, Clone)]
+enum FilterLiteral {
+    Number(f64),
+    Str(String),
+    Bool(bool),
+    Null,
+}
+
+#[derive(Debug, Clone)]
+enum FilterExpr {
+    Compare {
+        field: String,
+        op: FilterOp,
+        value: FilterLiteral,
+    },
+    And(Box<FilterExpr>, Box<FilterExpr>),
+    Or(Box<FilterExpr>, Box<FilterExpr>),
+}
+
+#[derive(Debug, Clone)]
+enum JsonPathStep {
+    Root,
+    Child(String),
+    Wildcard,
+    RecursiveDescent,
+    Index(i64),
+    Slice(Option<i64>, Option<i64>, i64),
+    Filter(FilterExpr),
+}
+
+fn parse_filter_literal(s: &str) -> FilterLiteral {
+    let s = s.trim();
+    if let Some(inner) = s.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')) {
+        FilterLiteral::Str(inner.to_string())
+    } else if let Some(inner) = s.strip_prefix('"').and_then(|s| s.strip_suffix('"')) {
+        FilterLiteral::Str(inner.to_string())
+    } else if s == "true" {
+        FilterLiteral::Bool(true)
+    } else if s == "false" {
+        FilterLiteral::Bool(false)
+    } else if s == "null" {
+        FilterLiteral::Null
+    } else if let Ok(n) = s.parse::<f64>() {
+        FilterLiteral::Number(n)
+    } else {
+        FilterLiteral::Str(s.to_string())
+    }
+}
+
+fn parse_filter_expr(body: &str) -> Result<FilterExpr, Box<dyn std::error::Error>> {
+    if let Some(idx) = body.find("&&") {
+        let (left, right) = (&body[..idx], &body[idx + 2..]);
+        return Ok(FilterExpr::And(
+            Box::new(parse_filter_expr(left)?),
+            Box::new(parse_filter_expr(right)?),
+        ));
+    }
+    if let Some(idx) = body.find("||") {
+        let (left, right) = (&body[..idx], &body[idx + 2..]);
+        return Ok(FilterExpr::Or(
+            Box::new(parse_filter_expr(left)?),
+            Box::new(parse_filter_expr(right)?),
+        ));
+    }
+
+    let body = body.trim();
+    const OPS: &[(&str, FilterOp)] = &[
+        ("==", FilterOp::Eq),
+        ("!=", FilterOp::Ne),
+        ("<=", FilterOp::Le),
+        (">=", FilterOp::Ge),
+        ("<", FilterOp::Lt),
+        (">", FilterOp::Gt),
+    ];
+    for (token, op) in OPS {
+        if let Some(idx) = body.find(token) {
+            let field = body[..idx]
+                .trim()
+                .trim_start_matches('@')
+                .trim_start_matches('.')
+                .to_string();
+            let value = parse_filter_literal(&body[idx + token.len()..]);
+            return Ok(FilterExpr::Compare {
+                field,
+                op: *op,
+                value,
+            });
+        }
+    }
+
+    Err(format!("Invalid JSONPath filter expression: '{}'", body).into())
+}
+
+fn eval_filter_expr(expr: &FilterExpr, candidate: &Value) -> bool {
+    match expr {
+        FilterExpr::And(l, r) => eval_filter_expr(l, candidate) && eval_filter_expr(r, candidate),
+        FilterExpr::Or(l, r) => eval_filter_expr(l, candidate) || eval_filter_expr(r, candidate),
+        FilterExpr::Compare { field, op, value } => {
+            let mut current = candidate;
+            for part in field.split('.') {
+                match current.get(part) {
+                    Some(v) => current = v,
+                    None => return false,
                 }
             }
+            compare_json_to_literal(current, op, value)
         }
-        Value::Number(_) | Value::Bool(_) => {
-            let str_data = data.to_string();
-            for pattern in value_patterns {
-                if pattern.is_match(&str_data) {
-                    matches.push(Match {
-                        key: normalize_key(prefix.trim_end_matches(delimiter), delimiter),
-                        value: data.clone(),
-                    });
+    }
+}
+
+fn compare_json_to_literal(actual: &Value, op: &FilterOp, expected: &FilterLiteral) -> bool {
+    let ordering = match (actual, expected) {
+        (Value::Number(a), FilterLiteral::Number(b)) => a.as_f64().unwrap_or(f64::NAN).partial_cmp(b),
+        (Value::String(a), FilterLiteral::Str(b)) => Some(a.as_str().cmp(b.as_str())),
+        (Value::Bool(a), FilterLiteral::Bool(b)) => Some(a.cmp(b)),
+        (Value::Null, FilterLiteral::Null) => Some(std::cmp::Ordering::Equal),
+        _ => None,
+    };
+
+    match ordering {
+        Some(ord) => match op {
+            FilterOp::Eq => ord == std::cmp::Ordering::Equal,
+            FilterOp::Ne => ord != std::cmp::Ordering::Equal,
+            FilterOp::Lt => ord == std::cmp::Ordering::Less,
+            FilterOp::Le => ord != std::cmp::Ordering::Greater,
+            FilterOp::Gt => ord == std::cmp::Ordering::Greater,
+            FilterOp::Ge => ord != std::cmp::Ordering::Less,
+        },
+        None => matches!(op, FilterOp::Ne),
+    }
+}
+
+fn tokenize_jsonpath(expr: &str) -> Result<Vec<JsonPathStep>, Box<dyn std::error::Error>> {
+    let chars: Vec<char> = expr.chars().collect();
+    let mut steps = Vec::new();
+    let mut i = 0;
+
+    if chars.first() == Some(&'$') {
+        steps.push(JsonPathStep::Root);
+        i += 1;
+    }
+
+    while i < chars.len() {
+        match chars[i] {
+            '.' => {
+                if chars.get(i + 1) == Some(&'.') {
+                    steps.push(JsonPathStep::RecursiveDescent);
+                    i += 2;
+                    // `..name` has no separating dot before the child name, unlike a
+                    // normal `.name` step, so parse it inline instead of looping back
+                    // around to the '.' match arm.
+                    if i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                        let start = i;
+                        while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                            i += 1;
+                        }
+                        let name: String = chars[start..i].iter().collect();
+                        if name == "*" {
+                            steps.push(JsonPathStep::Wildcard);
+                        } else if !name.is_empty() {
+                            steps.push(JsonPathStep::Child(name));
+                        }
+                    }
+                } else {
+                    i += 1;
+                    let start = i;
+                    while i < chars.len() && chars[i] != '.' && chars[i] != '[' {
+                        i += 1;
+                    }
+                    let name: String = chars[start..i].iter().collect();
+                    if name == "*" {
+                        steps.push(JsonPathStep::Wildcard);
+                    } else if !name.is_empty() {
+                        steps.push(JsonPathStep::Child(name));
+                    }
                 }
             }
+            '[' => {
+                let close = chars[i..]
+                    .iter()
+                    .position(|&c| c == ']')
+                    .map(|p| i + p)
+                    .ok_or("Unterminated '[' in JSONPath expression")?;
+                let inner: String = chars[i + 1..close].iter().collect();
+                let inner = inner.trim();
+
+                if let Some(filter) = inner.strip_prefix("?(").and_then(|s| s.strip_suffix(')')) {
+                    steps.push(JsonPathStep::Filter(parse_filter_expr(filter)?));
+                } else if inner == "*" {
+                    steps.push(JsonPathStep::Wildcard);
+                } else if let Some(name) = inner
+                    .strip_prefix('\'')
+                    .and_then(|s| s.strip_suffix('\''))
+                    .or_else(|| inner.strip_prefix('"').and_then(|s| s.strip_suffix('"')))
+                {
+                    steps.push(JsonPathStep::Child(name.to_string()));
+                } else if inner.contains(':') {
+                    let parts: Vec<&str> = inner.split(':').collect();
+                    let parse_opt = |s: &str| -> Option<i64> {
+                        if s.is_empty() {
+                            None
+                        } else {
+                            s.parse::<i64>().ok()
+                        }
+                    };
+                    let start = parts.first().and_then(|s| parse_opt(s));
+                    let end = parts.get(1).and_then(|s| parse_opt(s));
+                    let step = parts.get(2).and_then(|s| parse_opt(s)).unwrap_or(1);
+                    steps.push(JsonPathStep::Slice(start, end, step));
+                } else {
+                    let idx = inner
+                        .parse::<i64>()
+                        .map_err(|_| format!("Invalid JSONPath index: '{}'", inner))?;
+                    steps.push(JsonPathStep::Index(idx));
+                }
+                i = close + 1;
+            }
+            _ => {
+                return Err(format!("Unexpected character '{}' in JSONPath expression", chars[i]).into());
+            }
+        }
+    }
+
+    Ok(steps)
+}
+
+fn resolve_array_index(len: usize, idx: i64) -> Option<usize> {
+    if idx >= 0 {
+        let idx = idx as usize;
+        if idx < len {
+            Some(idx)
+        } else {
+            None
+        }
+    } else {
+        let idx = (len as i64) + idx;
+        if idx >= 0 {
+            Some(idx as usize)
+        } else {
+            None
+        }
+    }
+}
+
+fn collect_descendants(path: &[String], value: &Value, out: &mut Vec<(Vec<String>, Value)>) {
+    out.push((path.to_vec(), value.clone()));
+    match value {
+        Value::Object(map) => {
+            for (k, v) in map {
+                let mut child_path = path.to_vec();
+                child_path.push(k.clone());
+                collect_descendants(&child_path, v, out);
+            }
+        }
+        Value::Array(arr) => {
+            for (i, v) in arr.iter().enumerate() {
+                let mut child_path = path.to_vec();
+                child_path.push(i.to_string());
+                collect_descendants(&child_path, v, out);
+            }
         }
         _ => {}
     }
+}
 
-    matches
+fn eval_jsonpath_step(step: &JsonPathStep, cursors: Vec<(Vec<String>, Value)>) -> Vec<(Vec<String>, Value)> {
+    match step {
+        JsonPathStep::Root => cursors,
+        JsonPathStep::Child(name) => cursors
+            .into_iter()
+            .filter_map(|(path, value)| match &value {
+                Value::Object(map) => map.get(name).map(|v| {
+                    let mut new_path = path.clone();
+                    new_path.push(name.clone());
+                    (new_path, v.clone())
+                }),
+                Value::Array(arr) => name.parse::<usize>().ok().and_then(|idx| arr.get(idx)).map(|v| {
+                    let mut new_path = path.clone();
+                    new_path.push(name.clone());
+                    (new_path, v.clone())
+                }),
+                _ => None,
+            })
+            .collect(),
+        JsonPathStep::Wildcard => cursors
+            .into_iter()
+            .flat_map(|(path, value)| -> Vec<(Vec<String>, Value)> {
+                match &value {
+                    Value::Object(map) => map
+                        .iter()
+                        .map(|(k, v)| {
+                            let mut new_path = path.clone();
+                            new_path.push(k.clone());
+                            (new_path, v.clone())
+                        })
+                        .collect(),
+                    Value::Array(arr) => arr
+                        .iter()
+                        .enumerate()
+                        .map(|(i, v)| {
+                            let mut new_path = path.clone();
+                            new_path.push(i.to_string());
+                            (new_path, v.clone())
+                        })
+                        .collect(),
+                    _ => Vec::new(),
+                }
+            })
+            .collect(),
+        JsonPathStep::RecursiveDescent => cursors
+            .into_iter()
+            .flat_map(|(path, value)| {
+                let mut out = Vec::new();
+                collect_descendants(&path, &value, &mut out);
+                out
+            })
+            .collect(),
+        JsonPathStep::Index(idx) => cursors
+            .into_iter()
+            .filter_map(|(path, value)| match &value {
+                Value::Array(arr) => resolve_array_index(arr.len(), *idx).map(|i| {
+                    let mut new_path = path.clone();
+                    new_path.push(i.to_string());
+                    (new_path, arr[i].clone())
+                }),
+                _ => None,
+            })
+            .collect(),
+        JsonPathStep::Slice(start, end, step) => cursors
+            .into_iter()
+            .flat_map(|(path, value)| -> Vec<(Vec<String>, Value)> {
+                let arr = match &value {
+                    Value::Array(arr) => arr,
+                    _ => return Vec::new(),
+                };
+                let len = arr.len() as i64;
+                let step = if *step == 0 { 1 } else { *step };
+                let mut out = Vec::new();
+                if step > 0 {
+                    let start = start.unwrap_or(0).max(0).min(len);
+                    let end = end.unwrap_or(len).max(0).min(len);
+                    let mut i = start;
+                    while i < end {
+                        if let Some(v) = arr.get(i as usize) {
+                            let mut p = path.clone();
+                            p.push(i.to_string());
+                            out.push((p, v.clone()));
+                        }
+                        i += step;
+                    }
+                } else {
+                    // A negative step walks the array backwards, so the defaults flip:
+                    // start from the last index and run down through index 0.
+                    let start = start.unwrap_or(len - 1).max(-1).min(len - 1);
+                    let end = end.map(|e| e.max(-1).min(len - 1)).unwrap_or(-1);
+                    let mut i = start;
+                    while i > end {
+                        if let Some(v) = arr.get(i as usize) {
+                            let mut p = path.clone();
+                            p.push(i.to_string());
+                            out.push((p, v.clone()));
+                        }
+                        i += step;
+                    }
+                }
+                out
+            })
+            .collect(),
+        JsonPathStep::Filter(expr) => cursors
+            .into_iter()
+            .flat_map(|(path, value)| -> Vec<(Vec<String>, Value)> {
+                match &value {
+                    Value::Array(arr) => arr
+                        .iter()
+                        .enumerate()
+                        .filter(|(_, v)| eval_filter_expr(expr, v))
+                        .map(|(i, v)| {
+                            let mut new_path = path.clone();
+                            new_path.push(i.to_string());
+                            (new_path, v.clone())
+                        })
+                        .collect(),
+                    _ => {
+                        if eval_filter_expr(expr, &value) {
+                            vec![(path, value)]
+                        } else {
+                            Vec::new()
+                        }
+                    }
+                }
+            })
+            .collect(),
+    }
 }
 
-fn process_json(
-    file_path: &Option<String>,
-    keys: &[String],
-    values: &[String],
-    extended_keys: &[String],
-    extended_values: &[String],
+// Walks a `delimiter`-separated path expression against the value tree. Each segment
+// is either a literal key, a numeric array index, or `*` to match every child at that
+// level; a missing key or out-of-range index simply prunes that branch.
+fn walk_path_query(
+    value: &Value,
+    segments: &[String],
+    path: &mut Vec<String>,
     delimiter: &str,
-    relative_keys: &[String],
-    relative_delimiter: &str,
+    out: &mut Vec<Match>,
+) {
+    let (segment, rest) = match segments.split_first() {
+        Some(split) => split,
+        None => {
+            out.push(Match {
+                key: path.join(delimiter),
+                value: value.clone(),
+            });
+            return;
+        }
+    };
+
+    match value {
+        Value::Object(map) => {
+            if segment == "*" {
+                for (k, v) in map {
+                    path.push(k.clone());
+                    walk_path_query(v, rest, path, delimiter, out);
+                    path.pop();
+                }
+            } else if let Some(v) = map.get(segment) {
+                path.push(segment.clone());
+                walk_path_query(v, rest, path, delimiter, out);
+                path.pop();
+            }
+        }
+        Value::Array(arr) => {
+            if segment == "*" {
+                for (i, v) in arr.iter().enumerate() {
+                    path.push(i.to_string());
+                    walk_path_query(v, rest, path, delimiter, out);
+                    path.pop();
+                }
+            } else if let Ok(idx) = segment.parse::<usize>() {
+                if let Some(v) = arr.get(idx) {
+                    path.push(segment.clone());
+                    walk_path_query(v, rest, path, delimiter, out);
+                    path.pop();
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+fn eval_path_query(data: &Value, expr: &str, delimiter: &str) -> Vec<Match> {
+    let segments = parse_key(expr, delimiter);
+    let mut path = Vec::new();
+    let mut out = Vec::new();
+    walk_path_query(data, &segments, &mut path, delimiter, &mut out);
+    out
+}
+
+fn search_jsonpath(data: &Value, expr: &str, delimiter: &str) -> Result<Vec<Match>, Box<dyn std::error::Error>> {
+    let steps = tokenize_jsonpath(expr)?;
+    let mut cursors = vec![(Vec::new(), data.clone())];
+    for step in &steps {
+        cursors = eval_jsonpath_step(step, cursors);
+    }
+
+    Ok(cursors
+        .into_iter()
+        .map(|(path, value)| Match {
+            key: path.join(delimiter),
+            value,
+        })
+        .collect())
+}
+
+// Bundles every `--key`/`--value`/format/search-mode flag process_json needs. Keeps
+// adding a new search flag (as chunk0-1, chunk0-3, chunk0-4, and chunk1-3 each did) from
+// growing process_json's parameter list without bound.
+struct SearchOptions {
+    file_path: Option<String>,
+    keys: Vec<String>,
+    values: Vec<String>,
+    extended_keys: Vec<String>,
+    extended_values: Vec<String>,
+    delimiter: String,
+    relative_keys: Vec<String>,
+    relative_delimiter: String,
     ignore_case: bool,
-) -> Result<Vec<Match>, Box<dyn std::error::Error>> {
-    let data = load_json(file_path)?;
+    jsonpath: Option<String>,
+    globs: Vec<String>,
+    iglobs: Vec<String>,
+    format: Option<String>,
+    path_query: Option<String>,
+}
+
+fn process_json(opts: &SearchOptions) -> Result<Vec<Match>, Box<dyn std::error::Error>> {
+    let delimiter = opts.delimiter.as_str();
+    let data = load_input(&opts.file_path, &opts.format)?;
     let mut matches = Vec::new();
 
-    if !keys.is_empty() || !extended_keys.is_empty() {
-        let mut key_patterns = Vec::new();
-        for p in keys {
-            let pattern = if ignore_case {
-                Regex::new(&format!("(?i)^{}$", p))
+    if let Some(expr) = &opts.jsonpath {
+        matches.extend(search_jsonpath(&data, expr, delimiter)?);
+    }
+
+    if let Some(expr) = &opts.path_query {
+        matches.extend(eval_path_query(&data, expr, delimiter));
+    }
+
+    if !opts.keys.is_empty() || !opts.extended_keys.is_empty() || !opts.globs.is_empty() || !opts.iglobs.is_empty() {
+        let mut strategies = Vec::new();
+        for p in &opts.keys {
+            let anchored = format!("^{}$", p);
+            strategies.push(if opts.ignore_case {
+                MatchStrategy::Regex(Regex::new(&format!("(?i){}", anchored))?)
             } else {
-                Regex::new(&format!("^{}$", p))
-            }?;
-            key_patterns.push(pattern);
+                classify_key_pattern(&anchored)?
+            });
         }
-        for p in extended_keys {
-            let pattern = if ignore_case {
+        for p in &opts.extended_keys {
+            let pattern = if opts.ignore_case {
                 Regex::new(&format!("(?i){}", p))
             } else {
                 Regex::new(p)
             }?;
-            key_patterns.push(pattern);
+            strategies.push(MatchStrategy::Regex(pattern));
         }
-        matches.extend(search_keys(&data, &key_patterns, delimiter, ""));
+        for g in &opts.globs {
+            let translated = glob_to_regex(g, delimiter);
+            strategies.push(if opts.ignore_case {
+                MatchStrategy::Regex(Regex::new(&format!("(?i){}", translated))?)
+            } else {
+                classify_key_pattern(&translated)?
+            });
+        }
+        for g in &opts.iglobs {
+            let translated = glob_to_regex(g, delimiter);
+            strategies.push(MatchStrategy::Regex(Regex::new(&format!("(?i){}", translated))?));
+        }
+
+        let key_set = RegexSet::new(strategies.iter().filter_map(|s| match s {
+            MatchStrategy::Regex(r) => Some(r.as_str()),
+            _ => None,
+        }))?;
+        matches.extend(search_keys(&data, &strategies, &key_set, delimiter, ""));
     }
 
-    if !values.is_empty() || !extended_values.is_empty() {
+    if !opts.values.is_empty() || !opts.extended_values.is_empty() {
         let mut value_patterns = Vec::new();
-        for p in values {
-            let pattern = if ignore_case {
+        for p in &opts.values {
+            let pattern = if opts.ignore_case {
                 Regex::new(&format!("(?i)^{}$", p))
             } else {
                 Regex::new(&format!("^{}$", p))
             }?;
             value_patterns.push(pattern);
         }
-        for p in extended_values {
-            let pattern = if ignore_case {
+        for p in &opts.extended_values {
+            let pattern = if opts.ignore_case {
                 Regex::new(&format!("(?i){}", p))
             } else {
                 Regex::new(p)
             }?;
             value_patterns.push(pattern);
         }
-        matches.extend(search_values(&data, &value_patterns, delimiter, ""));
+        let value_set = RegexSet::new(value_patterns.iter().map(|p| p.as_str()))?;
+        matches.extend(search_values(&data, &value_set, delimiter, ""));
     }
 
-    if !relative_keys.is_empty() {
+    if !opts.relative_keys.is_empty() {
         matches = apply_relative_keys(
             &data,
             &matches,
-            relative_keys,
+            &opts.relative_keys,
             delimiter,
-            relative_delimiter,
+            &opts.relative_delimiter,
         );
     }
 
     Ok(matches)
 }
 
+#[derive(Debug, Clone)]
+enum DiffKind {
+    Added(Value),
+    Removed(Value),
+    Changed(Value, Value),
+}
+
+#[derive(Debug, Clone)]
+struct DiffEntry {
+    key: String,
+    kind: DiffKind,
+}
+
+// Recursively walks two value trees in parallel, building the same `delimiter`-joined
+// path that search_keys/search_values use. Objects diff over the union of their keys,
+// arrays diff index-by-index, and anything else (including a type mismatch) is compared
+// for equality and recorded as a single change at that path.
+fn diff_values(old: &Value, new: &Value, delimiter: &str, prefix: &str, out: &mut Vec<DiffEntry>) {
+    match (old, new) {
+        (Value::Object(old_map), Value::Object(new_map)) => {
+            let mut keys: Vec<&String> = old_map.keys().chain(new_map.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                let path = if prefix.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{}{}{}", prefix, delimiter, key)
+                };
+                match (old_map.get(key), new_map.get(key)) {
+                    (Some(o), Some(n)) => diff_values(o, n, delimiter, &path, out),
+                    (Some(o), None) => out.push(DiffEntry {
+                        key: path,
+                        kind: DiffKind::Removed(o.clone()),
+                    }),
+                    (None, Some(n)) => out.push(DiffEntry {
+                        key: path,
+                        kind: DiffKind::Added(n.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        (Value::Array(old_arr), Value::Array(new_arr)) => {
+            let max_len = old_arr.len().max(new_arr.len());
+            for i in 0..max_len {
+                let path = if prefix.is_empty() {
+                    i.to_string()
+                } else {
+                    format!("{}{}{}", prefix, delimiter, i)
+                };
+                match (old_arr.get(i), new_arr.get(i)) {
+                    (Some(o), Some(n)) => diff_values(o, n, delimiter, &path, out),
+                    (Some(o), None) => out.push(DiffEntry {
+                        key: path,
+                        kind: DiffKind::Removed(o.clone()),
+                    }),
+                    (None, Some(n)) => out.push(DiffEntry {
+                        key: path,
+                        kind: DiffKind::Added(n.clone()),
+                    }),
+                    (None, None) => {}
+                }
+            }
+        }
+        _ => {
+            if old != new {
+                out.push(DiffEntry {
+                    key: prefix.to_string(),
+                    kind: DiffKind::Changed(old.clone(), new.clone()),
+                });
+            }
+        }
+    }
+}
+
+fn render_diff_entry(entry: &DiffEntry, delimiter: &str) -> String {
+    let path = normalize_key(&entry.key, delimiter);
+    match &entry.kind {
+        DiffKind::Added(v) => format!("{}: + {}", path, serde_json::to_string(v).unwrap_or_default()),
+        DiffKind::Removed(v) => format!("{}: - {}", path, serde_json::to_string(v).unwrap_or_default()),
+        DiffKind::Changed(old, new) => format!(
+            "{}: {} -> {}",
+            path,
+            serde_json::to_string(old).unwrap_or_default(),
+            serde_json::to_string(new).unwrap_or_default()
+        ),
+    }
+}
+
+fn run_diff(
+    file_path: &Option<String>,
+    diff_path: &str,
+    format: &Option<String>,
+    delimiter: &str,
+) -> Result<(Vec<String>, bool), Box<dyn std::error::Error>> {
+    let old_data = load_input(file_path, format)?;
+    let new_data = load_input(&Some(diff_path.to_string()), format)?;
+    let mut entries = Vec::new();
+    diff_values(&old_data, &new_data, delimiter, "", &mut entries);
+    let has_diff = !entries.is_empty();
+    let lines = entries
+        .iter()
+        .map(|e| render_diff_entry(e, delimiter))
+        .collect();
+    Ok((lines, has_diff))
+}
+
+// Recurses through a template value, checking that every key/scalar it defines is
+// present at the same relative path in the target. Objects require each template key
+// to exist in the corresponding target object (extra target keys are ignored); arrays
+// are matched element-wise up to the template's length; anything else is compared for
+// equality. A missing key, short array, or type mismatch records a divergent path and
+// prunes that branch instead of recursing further.
+fn check_contains(
+    template: &Value,
+    target: &Value,
+    delimiter: &str,
+    prefix: &str,
+    matches: &mut Vec<Match>,
+    failures: &mut Vec<String>,
+) {
+    match template {
+        Value::Object(template_map) => match target {
+            Value::Object(target_map) => {
+                for (key, t_val) in template_map {
+                    let path = if prefix.is_empty() {
+                        key.clone()
+                    } else {
+                        format!("{}{}{}", prefix, delimiter, key)
+                    };
+                    match target_map.get(key) {
+                        Some(target_val) => {
+                            check_contains(t_val, target_val, delimiter, &path, matches, failures)
+                        }
+                        None => failures.push(path),
+                    }
+                }
+            }
+            _ => failures.push(prefix.to_string()),
+        },
+        Value::Array(template_arr) => match target {
+            Value::Array(target_arr) => {
+                for (i, t_val) in template_arr.iter().enumerate() {
+                    let path = if prefix.is_empty() {
+                        i.to_string()
+                    } else {
+                        format!("{}{}{}", prefix, delimiter, i)
+                    };
+                    match target_arr.get(i) {
+                        Some(target_val) => {
+                            check_contains(t_val, target_val, delimiter, &path, matches, failures)
+                        }
+                        None => failures.push(path),
+                    }
+                }
+            }
+            _ => failures.push(prefix.to_string()),
+        },
+        _ => {
+            if template == target {
+                matches.push(Match {
+                    key: prefix.to_string(),
+                    value: target.clone(),
+                });
+            } else {
+                failures.push(prefix.to_string());
+            }
+        }
+    }
+}
+
+fn run_contains(
+    file_path: &Option<String>,
+    template_path: &str,
+    format: &Option<String>,
+    delimiter: &str,
+) -> Result<(Vec<String>, bool), Box<dyn std::error::Error>> {
+    let target_data = load_input(file_path, format)?;
+    let template_data = load_input(&Some(template_path.to_string()), format)?;
+    let mut matches = Vec::new();
+    let mut failures = Vec::new();
+    check_contains(&template_data, &target_data, delimiter, "", &mut matches, &mut failures);
+    let success = failures.is_empty();
+    let lines = if success {
+        matches
+            .iter()
+            .map(|m| {
+                format!(
+                    "{}: {}",
+                    normalize_key(&m.key, delimiter),
+                    serde_json::to_string(&m.value).unwrap_or_default()
+                )
+            })
+            .collect()
+    } else {
+        failures.iter().map(|p| normalize_key(p, delimiter)).collect()
+    };
+    Ok((lines, success))
+}
+
 fn adjust_indent(json_str: &str, indent: usize) -> String {
     let mut result = Vec::new();
     for line in json_str.lines() {
@@ -497,6 +1631,56 @@ fn adjust_indent(json_str: &str, indent: usize) -> String {
     result.join("\n")
 }
 
+fn render_output(matches: &[Match], delimiter: &str, indent: Option<usize>, output: &str) -> String {
+    match output {
+        "json" => {
+            let entries: Vec<Value> = matches
+                .iter()
+                .map(|m| {
+                    serde_json::json!({
+                        "path": normalize_key(&m.key, delimiter),
+                        "value": m.value,
+                    })
+                })
+                .collect();
+            let pretty = serde_json::to_string_pretty(&Value::Array(entries)).unwrap_or_default();
+            match indent {
+                Some(level) => adjust_indent(&pretty, level),
+                None => pretty,
+            }
+        }
+        "ndjson" => matches
+            .iter()
+            .map(|m| {
+                serde_json::to_string(&serde_json::json!({
+                    "path": normalize_key(&m.key, delimiter),
+                    "value": m.value,
+                }))
+                .unwrap_or_default()
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+        "paths" => matches
+            .iter()
+            .map(|m| normalize_key(&m.key, delimiter))
+            .collect::<Vec<_>>()
+            .join("\n"),
+        _ => matches
+            .iter()
+            .map(|m| {
+                let json_output = if let Some(indent_level) = indent {
+                    let pretty = serde_json::to_string_pretty(&m.value).unwrap_or_else(|_| m.value.to_string());
+                    adjust_indent(&pretty, indent_level)
+                } else {
+                    serde_json::to_string(&m.value).unwrap_or_else(|_| m.value.to_string())
+                };
+                format!("{}: {}", normalize_key(&m.key, delimiter), json_output)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    }
+}
+
 fn main() {
     let args = parse_args();
     let mut keys = args.keys;
@@ -510,6 +1694,14 @@ fn main() {
     let ignore_case = args.ignore_case;
     let indent = args.indent;
     let count = args.count;
+    let jsonpath = args.jsonpath;
+    let glob = args.glob;
+    let iglob = args.iglob;
+    let format = args.format;
+    let output = args.output;
+    let path_query = args.path_query;
+    let diff = args.diff;
+    let contains = args.contains;
 
     let file = match args.file.as_deref() {
         // Use the given path
@@ -533,23 +1725,59 @@ fn main() {
         }
     };
 
-    if keys.is_empty() && extended_keys.is_empty() && values.is_empty() && extended_values.is_empty()
+    if let Some(diff_path) = diff {
+        let (lines, has_diff) = match run_diff(&file, &diff_path, &format, &delimiter) {
+            Ok(result) => result,
+            Err(_) => process::exit(1),
+        };
+        if !lines.is_empty() {
+            println!("{}", lines.join("\n"));
+        }
+        process::exit(if has_diff { 1 } else { 0 });
+    }
+
+    if let Some(template_path) = contains {
+        let (lines, success) = match run_contains(&file, &template_path, &format, &delimiter) {
+            Ok(result) => result,
+            Err(_) => process::exit(1),
+        };
+        if !lines.is_empty() {
+            println!("{}", lines.join("\n"));
+        }
+        process::exit(if success { 0 } else { 1 });
+    }
+
+    if keys.is_empty()
+        && extended_keys.is_empty()
+        && values.is_empty()
+        && extended_values.is_empty()
+        && jsonpath.is_none()
+        && glob.is_empty()
+        && iglob.is_empty()
+        && path_query.is_none()
     {
         eprintln!("Error: At least one key or value pattern must be specified");
         process::exit(1);
     }
 
-    let matches = match process_json(
-        &file,
-        &keys,
-        &values,
-        &extended_keys,
-        &extended_values,
-        &delimiter,
-        &relative_keys,
-        &relative_delimiter,
+    let search_options = SearchOptions {
+        file_path: file,
+        keys,
+        values,
+        extended_keys,
+        extended_values,
+        delimiter: delimiter.clone(),
+        relative_keys,
+        relative_delimiter,
         ignore_case,
-    ) {
+        jsonpath,
+        globs: glob,
+        iglobs: iglob,
+        format,
+        path_query,
+    };
+
+    let matches = match process_json(&search_options) {
         Ok(m) => m,
         Err(_) => process::exit(1),
     };
@@ -557,17 +1785,10 @@ fn main() {
     let total_count = matches.len();
     if count {
         println!("{}", total_count);
-    } else if total_count > 0 {
-        for m in &matches {
-            let json_output = if let Some(indent_level) = indent {
-                let pretty = serde_json::to_string_pretty(&m.value)
-                    .unwrap_or_else(|_| m.value.to_string());
-                adjust_indent(&pretty, indent_level)
-            } else {
-                serde_json::to_string(&m.value).unwrap_or_else(|_| m.value.to_string())
-            };
-            println!("{}: {}", normalize_key(&m.key, &delimiter), json_output);
-        }
+    } else if total_count > 0 || output == "json" {
+        // `--output json` promises a single JSON array, so it must still print `[]`
+        // on zero matches rather than silently producing no output at all.
+        println!("{}", render_output(&matches, &delimiter, indent, &output));
     }
 
     // Exit with 0 even if no matches, unless an error occurred
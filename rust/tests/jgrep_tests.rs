@@ -304,6 +304,390 @@ fn test_invalid_json() {
     assert_eq!(rc, 1, "Exit code mismatch for invalid JSON");
 }
 
+#[test]
+fn test_jsonpath_filter() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[("phoneNumbers::0::number", JSON_VALUE["phoneNumbers"][0]["number"].clone())],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(
+        &[file_path, "--jsonpath", "$.phoneNumbers[?(@.type == 'home')].number"],
+        None,
+    );
+    assert_eq!(stdout, expected, "JSONPath filter match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for JSONPath filter");
+}
+
+#[test]
+fn test_jsonpath_recursive_descent() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[
+            ("phoneNumbers::0::type", JSON_VALUE["phoneNumbers"][0]["type"].clone()),
+            ("phoneNumbers::1::type", JSON_VALUE["phoneNumbers"][1]["type"].clone()),
+        ],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--jsonpath", "$..type"], None);
+    assert_eq!(stdout, expected, "JSONPath recursive descent match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for JSONPath recursive descent");
+}
+
+#[test]
+fn test_glob_deep_match() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[
+            ("phoneNumbers::0::number", JSON_VALUE["phoneNumbers"][0]["number"].clone()),
+            ("phoneNumbers::1::number", JSON_VALUE["phoneNumbers"][1]["number"].clone()),
+        ],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--glob", "phoneNumbers::**::number"], None);
+    assert_eq!(stdout, expected, "Glob deep match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for glob deep match");
+}
+
+#[test]
+fn test_iglob_case_insensitive() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("address::street", JSON_VALUE["address"]["street"].clone())], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--iglob", "ADDRESS::STREET"], None);
+    assert_eq!(stdout, expected, "Case-insensitive glob match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for iglob match");
+}
+
+#[test]
+fn test_recfile_format() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(
+        temp_file,
+        "%rec: Contact\n\nName: Jane Smith\nPhone: 212-555-1234\nPhone: 646-555-5678\n"
+    )
+    .expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("0::Name", Value::String("Jane Smith".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--format", "rec", "0::Name"], None);
+    assert_eq!(stdout, expected, "Recfile format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for recfile format");
+}
+
+#[test]
+fn test_yaml_format() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "host: localhost\nport: 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--format", "yaml", "host"], None);
+    assert_eq!(stdout, expected, "YAML format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for YAML format");
+}
+
+#[test]
+fn test_toml_format() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "host = \"localhost\"\nport = 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--format", "toml", "host"], None);
+    assert_eq!(stdout, expected, "TOML format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for TOML format");
+}
+
+#[test]
+fn test_ini_format() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "[server]\nhost = localhost\nport = 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("server::host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--format", "ini", "server::host"], None);
+    assert_eq!(stdout, expected, "INI format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for INI format");
+}
+
+#[test]
+fn test_ini_format_sniffed_without_extension() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "[server]\nhost = localhost\nport = 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("server::host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "server::host"], None);
+    assert_eq!(stdout, expected, "Sniffed INI format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for sniffed INI format");
+}
+
+#[test]
+fn test_yaml_format_sniffed_without_extension() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "host: localhost\nport: 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "host"], None);
+    assert_eq!(stdout, expected, "Sniffed YAML format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for sniffed YAML format");
+}
+
+#[test]
+fn test_toml_format_sniffed_without_extension() {
+    let mut temp_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(temp_file, "host = \"localhost\"\nport = 8080\n").expect("Failed to write to temp file");
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("host", Value::String("localhost".to_string()))], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "host"], None);
+    assert_eq!(stdout, expected, "Sniffed TOML format match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for sniffed TOML format");
+}
+
+#[test]
+fn test_unknown_format_errors() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let (stdout, stderr, rc) = run_jgrep(&[file_path, "--format", "xml", "name"], None);
+    assert_eq!(stdout, "", "Unknown format output mismatch");
+    assert!(stderr.contains("Unknown format"), "Unknown format error message missing");
+    assert_eq!(rc, 1, "Exit code mismatch for unknown format");
+}
+
+#[test]
+fn test_output_ndjson() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format!(
+        "{{\"path\":\"name\",\"value\":{}}}",
+        serde_json::to_string(&JSON_VALUE["name"]).unwrap()
+    ) + "\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "name", "--output", "ndjson"], None);
+    assert_eq!(stdout, expected, "NDJSON output failed");
+    assert_eq!(rc, 0, "Exit code mismatch for ndjson output");
+}
+
+#[test]
+fn test_output_json_array() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected_value = serde_json::json!([
+        {"path": "name", "value": JSON_VALUE["name"].clone()},
+    ]);
+    let expected = serde_json::to_string_pretty(&expected_value).unwrap() + "\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "name", "--output", "json"], None);
+    assert_eq!(stdout, expected, "JSON array output failed");
+    assert_eq!(rc, 0, "Exit code mismatch for json output");
+}
+
+#[test]
+fn test_output_json_array_empty_on_no_matches() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = "[]\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--key", "nomatch", "--output", "json"], None);
+    assert_eq!(stdout, expected, "Empty JSON array output failed");
+    assert_eq!(rc, 0, "Exit code mismatch for json output with no matches");
+}
+
+#[test]
+fn test_output_paths() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = "address::street\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "address::street", "--output", "paths"], None);
+    assert_eq!(stdout, expected, "Paths output failed");
+    assert_eq!(rc, 0, "Exit code mismatch for paths output");
+}
+
+#[test]
+fn test_literal_key_fast_path() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(&[("name", JSON_VALUE["name"].clone())], None);
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "name"], None);
+    assert_eq!(stdout, expected, "Literal key fast path failed");
+    assert_eq!(rc, 0, "Exit code mismatch for literal key fast path");
+}
+
+#[test]
+fn test_prefix_key_fast_path() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[
+            ("phoneNumbers", JSON_VALUE["phoneNumbers"].clone()),
+            ("phoneNumbers::0", JSON_VALUE["phoneNumbers"][0].clone()),
+            ("phoneNumbers::0::type", JSON_VALUE["phoneNumbers"][0]["type"].clone()),
+            ("phoneNumbers::0::number", JSON_VALUE["phoneNumbers"][0]["number"].clone()),
+            ("phoneNumbers::1", JSON_VALUE["phoneNumbers"][1].clone()),
+            ("phoneNumbers::1::type", JSON_VALUE["phoneNumbers"][1]["type"].clone()),
+            ("phoneNumbers::1::number", JSON_VALUE["phoneNumbers"][1]["number"].clone()),
+        ],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "phone.*"], None);
+    assert_eq!(stdout, expected, "Prefix key fast path failed");
+    assert_eq!(rc, 0, "Exit code mismatch for prefix key fast path");
+}
+
+#[test]
+fn test_path_query_wildcard() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[
+            ("phoneNumbers::0::number", JSON_VALUE["phoneNumbers"][0]["number"].clone()),
+            ("phoneNumbers::1::number", JSON_VALUE["phoneNumbers"][1]["number"].clone()),
+        ],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--path", "phoneNumbers::*::number"], None);
+    assert_eq!(stdout, expected, "Path query wildcard failed");
+    assert_eq!(rc, 0, "Exit code mismatch for path query wildcard");
+}
+
+#[test]
+fn test_path_query_index() {
+    let temp_file = setup_example_json();
+    let file_path = temp_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[("phoneNumbers::1::type", JSON_VALUE["phoneNumbers"][1]["type"].clone())],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[file_path, "--path", "phoneNumbers::1::type"], None);
+    assert_eq!(stdout, expected, "Path query index failed");
+    assert_eq!(rc, 0, "Exit code mismatch for path query index");
+}
+
+#[test]
+fn test_diff_reports_added_removed_changed() {
+    let base_file = setup_example_json();
+    let base_path = base_file.path().to_str().unwrap();
+
+    let mut other_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(
+        other_file,
+        r#"{{
+            "name": "Jane Doe",
+            "address": {{
+                "street": "123 Main St",
+                "city": "New York",
+                "postalCode": "10001"
+            }},
+            "phoneNumbers": [
+                {{
+                    "type": "home",
+                    "number": "212-555-1234"
+                }}
+            ],
+            "email": "jane@example.com"
+        }}"#
+    )
+    .expect("Failed to write to temp file");
+    let other_path = other_file.path().to_str().unwrap();
+
+    let expected = format!(
+        "{}\n{}\n{}\n{}",
+        "age: - 25",
+        "email: + \"jane@example.com\"",
+        "name: \"Jane Smith\" -> \"Jane Doe\"",
+        "phoneNumbers::1: - {\"number\":\"646-555-5678\",\"type\":\"work\"}",
+    ) + "\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[base_path, "--diff", other_path], None);
+    assert_eq!(stdout, expected, "Diff output failed");
+    assert_eq!(rc, 1, "Exit code mismatch for diff with changes");
+}
+
+#[test]
+fn test_diff_no_changes() {
+    let base_file = setup_example_json();
+    let base_path = base_file.path().to_str().unwrap();
+    let other_file = setup_example_json();
+    let other_path = other_file.path().to_str().unwrap();
+
+    let (stdout, _stderr, rc) = run_jgrep(&[base_path, "--diff", other_path], None);
+    assert_eq!(stdout, "", "Diff output should be empty when identical");
+    assert_eq!(rc, 0, "Exit code mismatch for diff with no changes");
+}
+
+#[test]
+fn test_contains_template_matches() {
+    let target_file = setup_example_json();
+    let target_path = target_file.path().to_str().unwrap();
+
+    let mut template_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(
+        template_file,
+        r#"{{
+            "name": "Jane Smith",
+            "address": {{
+                "city": "New York"
+            }},
+            "phoneNumbers": [
+                {{
+                    "type": "home"
+                }}
+            ]
+        }}"#
+    )
+    .expect("Failed to write to temp file");
+    let template_path = template_file.path().to_str().unwrap();
+
+    let expected = format_output(
+        &[
+            ("address::city", JSON_VALUE["address"]["city"].clone()),
+            ("name", JSON_VALUE["name"].clone()),
+            ("phoneNumbers::0::type", JSON_VALUE["phoneNumbers"][0]["type"].clone()),
+        ],
+        None,
+    );
+    let (stdout, _stderr, rc) = run_jgrep(&[target_path, "--contains", template_path], None);
+    assert_eq!(stdout, expected, "Contains template match failed");
+    assert_eq!(rc, 0, "Exit code mismatch for matching contains template");
+}
+
+#[test]
+fn test_contains_template_diverges() {
+    let target_file = setup_example_json();
+    let target_path = target_file.path().to_str().unwrap();
+
+    let mut template_file = NamedTempFile::new().expect("Failed to create temp file");
+    writeln!(
+        template_file,
+        r#"{{
+            "name": "Someone Else",
+            "email": "missing@example.com"
+        }}"#
+    )
+    .expect("Failed to write to temp file");
+    let template_path = template_file.path().to_str().unwrap();
+
+    let expected = "email\nname\n";
+    let (stdout, _stderr, rc) = run_jgrep(&[target_path, "--contains", template_path], None);
+    assert_eq!(stdout, expected, "Contains template divergence failed");
+    assert_eq!(rc, 1, "Exit code mismatch for diverging contains template");
+}
+
 #[test]
 fn test_no_keys_or_values() {
     let temp_file = setup_example_json();